@@ -0,0 +1,31 @@
+use napi::JsFunction;
+use napi_derive::napi;
+
+mod plugins;
+
+pub use plugins::{JsAssetEmitted, JsHookKind, JsHooksAdapter};
+
+/// JS callbacks for each hook `JsHooksAdapter` bridges into `rspack_core::Plugin`, plus a
+/// `<hook>_blocking` flag per hook controlling whether its tsfn is called with
+/// `ThreadsafeFunctionCallMode::Blocking` or `NonBlocking` (see `JsHooksAdapter::from_js_hooks`).
+/// A missing blocking flag (`None`) falls back to `NonBlocking`.
+#[napi(object)]
+pub struct JsHooks {
+  pub process_assets: JsFunction,
+  pub this_compilation: JsFunction,
+  pub compilation: JsFunction,
+  pub emit: JsFunction,
+  pub after_emit: JsFunction,
+  pub should_emit: JsFunction,
+  pub render_manifest: JsFunction,
+  pub asset_emitted: JsFunction,
+
+  pub compilation_blocking: Option<bool>,
+  pub this_compilation_blocking: Option<bool>,
+  pub process_assets_blocking: Option<bool>,
+  pub emit_blocking: Option<bool>,
+  pub after_emit_blocking: Option<bool>,
+  pub should_emit_blocking: Option<bool>,
+  pub render_manifest_blocking: Option<bool>,
+  pub asset_emitted_blocking: Option<bool>,
+}
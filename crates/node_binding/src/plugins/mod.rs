@@ -2,7 +2,9 @@ use std::fmt::Debug;
 use std::pin::Pin;
 
 use async_trait::async_trait;
-use napi::{Env, NapiRaw, Result};
+use napi::bindgen_prelude::Buffer;
+use napi::{Env, JsFunction, JsUnknown, NapiRaw, Result, ValueType};
+use napi_derive::napi;
 use rspack_error::{internal_error, Error};
 
 use crate::threadsafe_function::{ThreadsafeFunction, ThreadsafeFunctionCallMode};
@@ -14,6 +16,72 @@ pub struct JsHooksAdapter {
   pub process_assets_tsfn: ThreadsafeFunction<(), ()>,
   pub emit_tsfn: ThreadsafeFunction<(), ()>,
   pub after_emit_tsfn: ThreadsafeFunction<(), ()>,
+  /// Bail-style hook: JS returns a boolean; `false` skips the JS `emit` hook call (it does not
+  /// stop the compiler from writing assets, see `emit`).
+  pub should_emit_tsfn: ThreadsafeFunction<(), bool>,
+  /// Waterfall-style hook: JS receives the current manifest entries and returns the ones that
+  /// should actually be fed back into the Rust pipeline, in order.
+  pub render_manifest_tsfn: ThreadsafeFunction<Vec<String>, Vec<String>>,
+  /// Called once per asset, instead of once for the whole `process_assets` stage. Note that our
+  /// own call site in `process_assets` below only has the full asset list available at once (no
+  /// incremental producer to drive this from), so in practice every asset is still queued back to
+  /// back rather than truly as each one is produced -- see `process_assets_stream`. Kept ref'd
+  /// only while [`Self::process_assets_stream`] is actively streaming, see that method.
+  pub asset_emitted_tsfn: ThreadsafeFunction<JsAssetEmitted, ()>,
+  call_modes: JsHooksCallModes,
+  /// Kept around so `ref_hook`/`unref_hook` and the streaming hooks can manage tsfn lifetime
+  /// without every caller having to thread an `Env` through from the original N-API entry point.
+  env: Env,
+}
+
+/// A single asset delivered to the streaming `process_assets_stream` hook. `source` is the raw
+/// asset bytes -- not coerced through UTF-8 -- so binary assets (images, wasm) round-trip intact.
+#[napi(object)]
+#[derive(Debug, Clone)]
+pub struct JsAssetEmitted {
+  pub filename: String,
+  pub source: Buffer,
+}
+
+/// Identifies one of `JsHooksAdapter`'s tsfns, for runtime `ref`/`unref` control. `string_enum` so
+/// JS can actually name a hook when calling into a `ref_hook`/`unref_hook` binding -- note that
+/// binding itself has to live on the JS-facing compiler/plugin class, which isn't part of this
+/// adapter file; `ref_hook`/`unref_hook` below are ready to be called from it.
+#[napi(string_enum)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JsHookKind {
+  Compilation,
+  ThisCompilation,
+  ProcessAssets,
+  Emit,
+  AfterEmit,
+  ShouldEmit,
+  RenderManifest,
+  AssetEmitted,
+}
+
+/// Per-hook `ThreadsafeFunctionCallMode`, resolved once in `from_js_hooks` from the blocking flags
+/// on `JsHooks`. `NonBlocking` is the default for every hook, matching the previous hardcoded
+/// behavior; a plugin that needs backpressure (e.g. `emit` writing to disk under a bounded queue)
+/// can opt a specific hook into `Blocking`.
+#[derive(Debug, Clone, Copy)]
+struct JsHooksCallModes {
+  compilation: ThreadsafeFunctionCallMode,
+  this_compilation: ThreadsafeFunctionCallMode,
+  process_assets: ThreadsafeFunctionCallMode,
+  emit: ThreadsafeFunctionCallMode,
+  after_emit: ThreadsafeFunctionCallMode,
+  should_emit: ThreadsafeFunctionCallMode,
+  render_manifest: ThreadsafeFunctionCallMode,
+  asset_emitted: ThreadsafeFunctionCallMode,
+}
+
+fn call_mode(blocking: Option<bool>) -> ThreadsafeFunctionCallMode {
+  if blocking.unwrap_or(false) {
+    ThreadsafeFunctionCallMode::Blocking
+  } else {
+    ThreadsafeFunctionCallMode::NonBlocking
+  }
 }
 
 impl Debug for JsHooksAdapter {
@@ -42,7 +110,7 @@ impl rspack_core::Plugin for JsHooksAdapter {
 
     self
       .compilation_tsfn
-      .call(compilation, ThreadsafeFunctionCallMode::NonBlocking)?
+      .call(compilation, self.call_modes.compilation)?
       .await
       .map_err(|err| {
         Error::InternalError(internal_error!(format!(
@@ -66,7 +134,7 @@ impl rspack_core::Plugin for JsHooksAdapter {
 
     self
       .this_compilation_tsfn
-      .call(compilation, ThreadsafeFunctionCallMode::NonBlocking)?
+      .call(compilation, self.call_modes.this_compilation)?
       .await
       .map_err(|err| {
         Error::InternalError(internal_error!(format!(
@@ -80,12 +148,39 @@ impl rspack_core::Plugin for JsHooksAdapter {
   async fn process_assets(
     &mut self,
     _ctx: rspack_core::PluginContext,
-    _args: rspack_core::ProcessAssetsArgs<'_>,
+    args: rspack_core::ProcessAssetsArgs<'_>,
   ) -> rspack_core::PluginProcessAssetsHookOutput {
+    let filenames: Vec<String> = args.compilation.assets.keys().cloned().collect();
+    let kept_filenames = self.render_manifest(filenames).await?;
+
+    // Feed the waterfall result back into the pipeline: anything JS dropped from the manifest is
+    // dropped from the compilation's own asset set, not just from what gets streamed below.
+    // Reordering the underlying asset map isn't attempted here -- `render_manifest`'s signature
+    // only carries filenames, not full assets, so there's nothing to add beyond what
+    // `args.compilation.assets` already has.
+    let kept: std::collections::HashSet<&str> =
+      kept_filenames.iter().map(String::as_str).collect();
+    args
+      .compilation
+      .assets
+      .retain(|filename, _| kept.contains(filename.as_str()));
+
+    let streamed_assets = kept_filenames
+      .into_iter()
+      .filter_map(|filename| {
+        let source = args.compilation.assets.get(&filename)?.source.as_ref()?;
+        Some(JsAssetEmitted {
+          source: source.buffer().into_owned().into(),
+          filename,
+        })
+      })
+      .collect::<Vec<_>>();
+    self.process_assets_stream(streamed_assets).await?;
+
     // Directly calling hook processAssets without converting assets to JsAssets, instead, we use APIs to get `Source` lazily on the Node side.
     self
       .process_assets_tsfn
-      .call((), ThreadsafeFunctionCallMode::NonBlocking)?
+      .call((), self.call_modes.process_assets)?
       .await
       .map_err(|err| {
         Error::InternalError(internal_error!(format!(
@@ -97,9 +192,17 @@ impl rspack_core::Plugin for JsHooksAdapter {
 
   #[tracing::instrument(name = "js_hooks_adapter::emit", skip_all)]
   async fn emit(&mut self, _: &mut rspack_core::Compilation) -> rspack_error::Result<()> {
+    // `should_emit` only gates whether JS gets notified via `emit_tsfn` below -- it can't stop
+    // rspack from writing assets to disk, since that happens elsewhere in the compiler's own
+    // emit stage. A real veto has to happen there, by calling `should_emit` before that stage
+    // runs at all; that orchestration lives outside this adapter.
+    if !self.should_emit().await? {
+      return Ok(());
+    }
+
     self
       .emit_tsfn
-      .call((), ThreadsafeFunctionCallMode::NonBlocking)?
+      .call((), self.call_modes.emit)?
       .await
       .map_err(|err| {
         Error::InternalError(internal_error!(format!(
@@ -113,7 +216,7 @@ impl rspack_core::Plugin for JsHooksAdapter {
   async fn after_emit(&mut self, _: &mut rspack_core::Compilation) -> rspack_error::Result<()> {
     self
       .after_emit_tsfn
-      .call((), ThreadsafeFunctionCallMode::NonBlocking)?
+      .call((), self.call_modes.after_emit)?
       .await
       .map_err(|err| {
         Error::InternalError(internal_error!(format!(
@@ -124,6 +227,181 @@ impl rspack_core::Plugin for JsHooksAdapter {
   }
 }
 
+impl JsHooksAdapter {
+  /// Webpack-compatible `shouldEmit` hook: a bail hook JS can use to skip notifying the `emit`
+  /// hook. Called from `emit` above before the `emit` hook itself runs. Matches webpack's default
+  /// of "emit unless a plugin explicitly says not to" -- a callback that returns `undefined`/`null`
+  /// (i.e. doesn't take a position) is treated as `true`, not coerced to `false`.
+  pub async fn should_emit(&mut self) -> rspack_error::Result<bool> {
+    self
+      .should_emit_tsfn
+      .call((), self.call_modes.should_emit)?
+      .await
+      .map_err(|err| {
+        Error::InternalError(internal_error!(format!(
+          "Failed to call should emit: {}",
+          err.to_string()
+        )))
+      })?
+  }
+
+  /// Webpack-compatible `renderManifest` hook: a waterfall hook JS can use to drop manifest
+  /// entries (a new order only changes the order assets are streamed in, and new entries are
+  /// ignored -- see the caller in `process_assets`). Called from `process_assets` above with the asset filenames computed so
+  /// far; entries left out of the result are removed from `compilation.assets` itself, before any
+  /// of the rest are streamed out via `process_assets_stream`.
+  pub async fn render_manifest(&mut self, entries: Vec<String>) -> rspack_error::Result<Vec<String>> {
+    self
+      .render_manifest_tsfn
+      .call(entries, self.call_modes.render_manifest)?
+      .await
+      .map_err(|err| {
+        Error::InternalError(internal_error!(format!(
+          "Failed to call render manifest: {}",
+          err.to_string()
+        )))
+      })?
+  }
+}
+
+/// Recover a human-readable message (and stack trace, when the thrown/rejected value is an
+/// `Error` instance) from a JS value so it can be surfaced in Rust-side diagnostics instead of a
+/// generic "callback failed" string.
+fn describe_js_error(value: JsUnknown) -> String {
+  if value.get_type() != Ok(ValueType::Object) {
+    return stringify_js_value(value);
+  }
+
+  // `coerce_to_object` consumes `value`, so the fallback below has to work off `object` (via
+  // `into_unknown`) instead of trying to reuse the original handle.
+  match value.coerce_to_object() {
+    Ok(object) => {
+      for property in ["stack", "message"] {
+        if let Ok(property_value) = object.get_named_property::<JsUnknown>(property) {
+          if property_value.get_type() == Ok(ValueType::String) {
+            if let Ok(message) = property_value
+              .coerce_to_string()
+              .and_then(|s| s.into_utf8())
+              .and_then(|s| s.as_str().map(str::to_owned))
+            {
+              return message;
+            }
+          }
+        }
+      }
+      stringify_js_value(object.into_unknown())
+    }
+    Err(_) => "Unknown error thrown from JS callback".to_string(),
+  }
+}
+
+fn stringify_js_value(value: JsUnknown) -> String {
+  value
+    .coerce_to_string()
+    .and_then(|s| s.into_utf8())
+    .and_then(|s| s.as_str().map(str::to_owned))
+    .unwrap_or_else(|_| "Unknown error thrown from JS callback".to_string())
+}
+
+/// The two shapes a failed JS callback can hand back: a rejected Promise (or thrown value) still
+/// carries the original `JsUnknown`, so `describe_js_error` can dig a `stack` out of it, while a
+/// synchronous throw only reaches us as a `napi::Error` whose `reason` is the message alone — by
+/// the time `call_js_function_with_napi_objects!` returns `Err`, N-API has already cleared the
+/// pending exception, so there's no value left to recover a stack from.
+enum JsFailure {
+  Value(JsUnknown),
+  Native(napi::Error),
+}
+
+/// Single entry point both failure shapes go through, so every hook reports failures the same way
+/// (falling back to message-only when the throw was synchronous instead of a rejected Promise).
+fn describe_js_failure(failure: JsFailure) -> String {
+  match failure {
+    JsFailure::Value(value) => describe_js_error(value),
+    JsFailure::Native(err) => err.reason,
+  }
+}
+
+/// If the JS callback returned a native Promise, defer `on_settle` until it settles instead of
+/// running it immediately with the (still pending) Promise object. Plain, synchronous return
+/// values, and non-Promise thenables, take the fast path on the first line and run `on_settle`
+/// right away.
+///
+/// `on_settle` is only ever invoked once, with either the fulfilled value or the rejection
+/// reason, mirroring how a synchronous callback result is handled below.
+fn settle_maybe_promise(
+  env: napi::sys::napi_env,
+  result: JsUnknown,
+  on_settle: impl FnOnce(std::result::Result<JsUnknown, JsUnknown>) -> Result<()> + 'static,
+) -> Result<()> {
+  if result.get_type()? != ValueType::Object || !result.is_promise()? {
+    return on_settle(Ok(result));
+  }
+
+  let env = Env::from_raw(env);
+  let promise = result.coerce_to_object()?;
+  let then: JsFunction = promise.get_named_property("then")?;
+
+  let on_settle = std::rc::Rc::new(std::cell::RefCell::new(Some(on_settle)));
+  let on_settle_rejected = on_settle.clone();
+
+  let on_fulfilled = env.create_function_from_closure("onFulfilled", move |ctx| {
+    if let Some(on_settle) = on_settle.borrow_mut().take() {
+      on_settle(Ok(ctx.get::<JsUnknown>(0)?))?;
+    }
+    ctx.env.get_undefined()
+  })?;
+  let on_rejected = env.create_function_from_closure("onRejected", move |ctx| {
+    if let Some(on_settle) = on_settle_rejected.borrow_mut().take() {
+      on_settle(Err(ctx.get::<JsUnknown>(0)?))?;
+    }
+    ctx.env.get_undefined()
+  })?;
+
+  then.call(
+    Some(&promise),
+    &[on_fulfilled.into_unknown(), on_rejected.into_unknown()],
+  )?;
+
+  Ok(())
+}
+
+/// Shared by every `ThreadsafeFunction::create` closure in `from_js_hooks`: awaits a Promise
+/// return value if there is one, then routes whatever the callback settled to -- a value, a
+/// rejected Promise, or a synchronous throw -- to `on_settle` as a single `JsFailure`-carrying
+/// outcome. This is the one place that needs to know about all three shapes a call can take;
+/// each hook only supplies how to turn its own success value into its own `R`.
+fn settle_into_resolver(
+  env: napi::sys::napi_env,
+  call_result: Result<JsUnknown>,
+  on_settle: impl FnOnce(std::result::Result<JsUnknown, JsFailure>) -> Result<()> + 'static,
+) -> Result<()> {
+  match call_result {
+    Ok(result) => settle_maybe_promise(env, result, move |settled| {
+      on_settle(settled.map_err(JsFailure::Value))
+    }),
+    // The JS callback threw synchronously; surface the same failure `on_settle` would get for a
+    // rejected Promise instead of leaving the resolver (and the awaiting Rust future) unsettled.
+    Err(err) => on_settle(Err(JsFailure::Native(err))),
+  }
+}
+
+/// Every hook's `Err` arm does the same thing regardless of its `R`: describe the failure and
+/// hand the resolver an inert `undefined` paired with a conversion that just propagates it as an
+/// `Error`. This is the piece of the 8 `settle_into_resolver` callbacks that's actually identical
+/// byte-for-byte; `resolver.resolve::<R>(..)` itself still has to be called at each site since the
+/// resolver's type differs per hook and isn't nameable here.
+fn failed_call<R: 'static>(
+  env: napi::sys::napi_env,
+  failure: JsFailure,
+) -> Result<(JsUnknown, impl FnOnce(JsUnknown) -> Result<R> + 'static)> {
+  let message = describe_js_failure(failure);
+  let undefined = Env::from_raw(env).get_undefined()?.into_unknown();
+  Ok((undefined, move |_: JsUnknown| {
+    Err(Error::InternalError(internal_error!(message)))
+  }))
+}
+
 impl JsHooksAdapter {
   pub fn from_js_hooks(env: Env, js_hooks: JsHooks) -> Result<Self> {
     let JsHooks {
@@ -132,8 +410,30 @@ impl JsHooksAdapter {
       compilation,
       emit,
       after_emit,
+      should_emit,
+      render_manifest,
+      asset_emitted,
+      compilation_blocking,
+      this_compilation_blocking,
+      process_assets_blocking,
+      emit_blocking,
+      after_emit_blocking,
+      should_emit_blocking,
+      render_manifest_blocking,
+      asset_emitted_blocking,
     } = js_hooks;
 
+    let call_modes = JsHooksCallModes {
+      compilation: call_mode(compilation_blocking),
+      this_compilation: call_mode(this_compilation_blocking),
+      process_assets: call_mode(process_assets_blocking),
+      emit: call_mode(emit_blocking),
+      after_emit: call_mode(after_emit_blocking),
+      should_emit: call_mode(should_emit_blocking),
+      render_manifest: call_mode(render_manifest_blocking),
+      asset_emitted: call_mode(asset_emitted_blocking),
+    };
+
     // *Note* that the order of the creation of threadsafe function is important. There is a queue of threadsafe calls for each tsfn:
     // For example:
     // tsfn1: [call-in-js-task1, call-in-js-task2]
@@ -152,9 +452,15 @@ impl JsHooksAdapter {
 
         let env = ctx.env;
         let cb = ctx.callback;
-        let result = unsafe { call_js_function_with_napi_objects!(env, cb, ctx.value) }?;
-
-        resolver.resolve::<()>(result, |_| Ok(()))
+        let call_result = unsafe { call_js_function_with_napi_objects!(env, cb, ctx.value) };
+
+        settle_into_resolver(env, call_result, move |settled| match settled {
+          Ok(value) => resolver.resolve::<()>(value, |_| Ok(())),
+          Err(failure) => {
+            let (value, convert) = failed_call::<()>(env, failure)?;
+            resolver.resolve(value, convert)
+          }
+        })
       })
     }?;
 
@@ -166,9 +472,15 @@ impl JsHooksAdapter {
 
         let env = ctx.env;
         let cb = ctx.callback;
-        let result = unsafe { call_js_function_with_napi_objects!(env, cb, ctx.value) }?;
-
-        resolver.resolve::<()>(result, |_| Ok(()))
+        let call_result = unsafe { call_js_function_with_napi_objects!(env, cb, ctx.value) };
+
+        settle_into_resolver(env, call_result, move |settled| match settled {
+          Ok(value) => resolver.resolve::<()>(value, |_| Ok(())),
+          Err(failure) => {
+            let (value, convert) = failed_call::<()>(env, failure)?;
+            resolver.resolve(value, convert)
+          }
+        })
       })
     }?;
 
@@ -180,9 +492,15 @@ impl JsHooksAdapter {
 
         let env = ctx.env;
         let cb = ctx.callback;
-        let result = unsafe { call_js_function_with_napi_objects!(env, cb, ctx.value) }?;
-
-        resolver.resolve::<()>(result, |_| Ok(()))
+        let call_result = unsafe { call_js_function_with_napi_objects!(env, cb, ctx.value) };
+
+        settle_into_resolver(env, call_result, move |settled| match settled {
+          Ok(value) => resolver.resolve::<()>(value, |_| Ok(())),
+          Err(failure) => {
+            let (value, convert) = failed_call::<()>(env, failure)?;
+            resolver.resolve(value, convert)
+          }
+        })
       })
     }?;
 
@@ -194,9 +512,15 @@ impl JsHooksAdapter {
 
         let env = ctx.env;
         let cb = ctx.callback;
-        let result = unsafe { call_js_function_with_napi_objects!(env, cb, ctx.value) }?;
-
-        resolver.resolve::<()>(result, |_| Ok(()))
+        let call_result = unsafe { call_js_function_with_napi_objects!(env, cb, ctx.value) };
+
+        settle_into_resolver(env, call_result, move |settled| match settled {
+          Ok(value) => resolver.resolve::<()>(value, |_| Ok(())),
+          Err(failure) => {
+            let (value, convert) = failed_call::<()>(env, failure)?;
+            resolver.resolve(value, convert)
+          }
+        })
       })
     }?;
 
@@ -208,9 +532,85 @@ impl JsHooksAdapter {
 
         let env = ctx.env;
         let cb = ctx.callback;
-        let result = unsafe { call_js_function_with_napi_objects!(env, cb, ctx.value) }?;
+        let call_result = unsafe { call_js_function_with_napi_objects!(env, cb, ctx.value) };
+
+        settle_into_resolver(env, call_result, move |settled| match settled {
+          Ok(value) => resolver.resolve::<()>(value, |_| Ok(())),
+          Err(failure) => {
+            let (value, convert) = failed_call::<()>(env, failure)?;
+            resolver.resolve(value, convert)
+          }
+        })
+      })
+    }?;
+
+    let mut should_emit_tsfn: ThreadsafeFunction<(), bool> = {
+      let cb = unsafe { should_emit.raw() };
+
+      ThreadsafeFunction::create(env.raw(), cb, 0, |ctx| {
+        let (ctx, resolver) = ctx.split_into_parts();
+
+        let env = ctx.env;
+        let cb = ctx.callback;
+        let call_result = unsafe { call_js_function_with_napi_objects!(env, cb, ctx.value) };
+
+        settle_into_resolver(env, call_result, move |settled| match settled {
+          Ok(value) => resolver.resolve::<bool>(value, |value| {
+            // No explicit position from JS (undefined/null, e.g. no shouldEmit callback
+            // registered) means "emit", matching webpack's default -- only an explicit falsy
+            // return should veto.
+            if matches!(value.get_type()?, ValueType::Undefined | ValueType::Null) {
+              return Ok(true);
+            }
+            value.coerce_to_bool()?.get_value().map_err(Into::into)
+          }),
+          Err(failure) => {
+            let (value, convert) = failed_call::<bool>(env, failure)?;
+            resolver.resolve(value, convert)
+          }
+        })
+      })
+    }?;
 
-        resolver.resolve::<()>(result, |_| Ok(()))
+    let mut render_manifest_tsfn: ThreadsafeFunction<Vec<String>, Vec<String>> = {
+      let cb = unsafe { render_manifest.raw() };
+
+      ThreadsafeFunction::create(env.raw(), cb, 0, |ctx| {
+        let (ctx, resolver) = ctx.split_into_parts();
+
+        let env = ctx.env;
+        let cb = ctx.callback;
+        let call_result = unsafe { call_js_function_with_napi_objects!(env, cb, ctx.value) };
+
+        settle_into_resolver(env, call_result, move |settled| match settled {
+          Ok(value) => resolver.resolve::<Vec<String>>(value, |value| {
+            Env::from_raw(env).from_js_value(value).map_err(Into::into)
+          }),
+          Err(failure) => {
+            let (value, convert) = failed_call::<Vec<String>>(env, failure)?;
+            resolver.resolve(value, convert)
+          }
+        })
+      })
+    }?;
+
+    let mut asset_emitted_tsfn: ThreadsafeFunction<JsAssetEmitted, ()> = {
+      let cb = unsafe { asset_emitted.raw() };
+
+      ThreadsafeFunction::create(env.raw(), cb, 0, |ctx| {
+        let (ctx, resolver) = ctx.split_into_parts();
+
+        let env = ctx.env;
+        let cb = ctx.callback;
+        let call_result = unsafe { call_js_function_with_napi_objects!(env, cb, ctx.value) };
+
+        settle_into_resolver(env, call_result, move |settled| match settled {
+          Ok(value) => resolver.resolve::<()>(value, |_| Ok(())),
+          Err(failure) => {
+            let (value, convert) = failed_call::<()>(env, failure)?;
+            resolver.resolve(value, convert)
+          }
+        })
       })
     }?;
 
@@ -220,6 +620,10 @@ impl JsHooksAdapter {
     after_emit_tsfn.unref(&env)?;
     compilation_tsfn.unref(&env)?;
     this_compilation_tsfn.unref(&env)?;
+    should_emit_tsfn.unref(&env)?;
+    render_manifest_tsfn.unref(&env)?;
+    // Only ref'd for the lifetime of a single `process_assets_stream` call, see that method.
+    asset_emitted_tsfn.unref(&env)?;
 
     Ok(JsHooksAdapter {
       process_assets_tsfn,
@@ -227,6 +631,94 @@ impl JsHooksAdapter {
       this_compilation_tsfn,
       emit_tsfn,
       after_emit_tsfn,
+      should_emit_tsfn,
+      render_manifest_tsfn,
+      asset_emitted_tsfn,
+      call_modes,
+      env,
     })
   }
+
+  /// Keep `hook`'s tsfn alive, i.e. keep the Node.js event loop running so the call queue can
+  /// still be drained. Pair with [`Self::unref_hook`] once the plugin has no more pending work for
+  /// that hook.
+  pub fn ref_hook(&mut self, hook: JsHookKind) -> Result<()> {
+    let env = &self.env;
+    match hook {
+      JsHookKind::Compilation => self.compilation_tsfn.refer(env),
+      JsHookKind::ThisCompilation => self.this_compilation_tsfn.refer(env),
+      JsHookKind::ProcessAssets => self.process_assets_tsfn.refer(env),
+      JsHookKind::Emit => self.emit_tsfn.refer(env),
+      JsHookKind::AfterEmit => self.after_emit_tsfn.refer(env),
+      JsHookKind::ShouldEmit => self.should_emit_tsfn.refer(env),
+      JsHookKind::RenderManifest => self.render_manifest_tsfn.refer(env),
+      JsHookKind::AssetEmitted => self.asset_emitted_tsfn.refer(env),
+    }
+  }
+
+  /// Let `hook`'s tsfn stop keeping the event loop alive, releasing it back to its default
+  /// (unref'd) state set up in `from_js_hooks`.
+  pub fn unref_hook(&mut self, hook: JsHookKind) -> Result<()> {
+    let env = &self.env;
+    match hook {
+      JsHookKind::Compilation => self.compilation_tsfn.unref(env),
+      JsHookKind::ThisCompilation => self.this_compilation_tsfn.unref(env),
+      JsHookKind::ProcessAssets => self.process_assets_tsfn.unref(env),
+      JsHookKind::Emit => self.emit_tsfn.unref(env),
+      JsHookKind::AfterEmit => self.after_emit_tsfn.unref(env),
+      JsHookKind::ShouldEmit => self.should_emit_tsfn.unref(env),
+      JsHookKind::RenderManifest => self.render_manifest_tsfn.unref(env),
+      JsHookKind::AssetEmitted => self.asset_emitted_tsfn.unref(env),
+    }
+  }
+
+  /// Streaming counterpart to `process_assets`: instead of firing once for the whole stage, calls
+  /// the registered JS callback once per asset. The tsfn is ref'd for the duration of the stream
+  /// so the event loop stays alive while calls are still in flight, and unref'd again once the
+  /// stage completes so it doesn't keep the process alive afterward.
+  ///
+  /// Each call is queued without awaiting the previous one first, so a slow JS callback for one
+  /// asset doesn't hold up the rest. Results are then awaited in order and the first failure is
+  /// returned; the results of calls queued after it are not awaited. This takes `impl IntoIterator`, so a
+  /// caller with a genuine incremental producer could drive it lazily -- our own caller below
+  /// only has a fully materialized `Vec` to hand it, since `process_assets` only runs once per
+  /// stage with the complete asset list already in hand.
+  pub async fn process_assets_stream(
+    &mut self,
+    assets: impl IntoIterator<Item = JsAssetEmitted>,
+  ) -> rspack_error::Result<()> {
+    self.ref_hook(JsHookKind::AssetEmitted)?;
+
+    let result = self.stream_assets(assets).await;
+
+    self.unref_hook(JsHookKind::AssetEmitted)?;
+
+    result
+  }
+
+  async fn stream_assets(
+    &mut self,
+    assets: impl IntoIterator<Item = JsAssetEmitted>,
+  ) -> rspack_error::Result<()> {
+    let calls = assets
+      .into_iter()
+      .map(|asset| {
+        self
+          .asset_emitted_tsfn
+          .call(asset, self.call_modes.asset_emitted)
+      })
+      .collect::<Result<Vec<_>>>()
+      .map_err(|err| Error::InternalError(internal_error!(err.to_string())))?;
+
+    for call in calls {
+      call.await.map_err(|err| {
+        Error::InternalError(internal_error!(format!(
+          "Failed to call process assets stream: {}",
+          err.to_string()
+        )))
+      })??;
+    }
+
+    Ok(())
+  }
 }